@@ -11,7 +11,7 @@ use super::{BasicParseError, ParseError, Parser, ToCss, Token};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// A color with red, green, blue, and alpha components, in a byte each.
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 #[repr(C)]
 pub struct RGBA {
     /// The red component.
@@ -130,7 +130,7 @@ impl ToCss for RGBA {
 }
 
 /// A <color> value.
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Color {
     /// The 'currentcolor' keyword
     CurrentColor,
@@ -74,7 +74,7 @@ pub use crate::cow_rc_str::CowRcStr;
 pub use crate::from_bytes::{stylesheet_encoding, EncodingSupport};
 #[doc(hidden)]
 pub use crate::macros::_cssparser_internal_to_lowercase;
-pub use crate::nth::parse_nth;
+pub use crate::nth::{parse_nth, serialize_nth};
 pub use crate::parser::{BasicParseError, BasicParseErrorKind, ParseError, ParseErrorKind};
 pub use crate::parser::{Delimiter, Delimiters, Parser, ParserInput, ParserState};
 pub use crate::rules_and_declarations::{parse_important, parse_one_declaration};
@@ -82,7 +82,10 @@ pub use crate::rules_and_declarations::{parse_one_rule, RuleListParser};
 pub use crate::rules_and_declarations::{AtRuleParser, QualifiedRuleParser};
 pub use crate::rules_and_declarations::{DeclarationListParser, DeclarationParser};
 pub use crate::serializer::{serialize_identifier, serialize_name, serialize_string};
-pub use crate::serializer::{CssStringWriter, ToCss, TokenSerializationType};
+pub use crate::serializer::{serialize_string_with_fewest_escapes, serialize_url};
+pub use crate::serializer::{serialize_string_with_quote_preference, QuotePreference};
+pub use crate::serializer::{serialize_url_with_quote_preference, CssStringWriter};
+pub use crate::serializer::{IoWriter, ToCss, TokenSerializationType};
 pub use crate::tokenizer::{SourceLocation, SourcePosition, Token};
 pub use crate::unicode_range::UnicodeRange;
 pub use cssparser_macros::*;
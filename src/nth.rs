@@ -2,8 +2,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use super::{BasicParseError, Parser, ParserInput, Token};
+use super::{BasicParseError, Parser, ParserInput, ToCss, Token};
 use matches::matches;
+use std::fmt;
 
 /// Parse the *An+B* notation, as found in the `:nth-child()` selector.
 /// The input is typically the arguments of a function,
@@ -82,6 +83,34 @@ pub fn parse_nth<'i, 't>(input: &mut Parser<'i, 't>) -> Result<(i32, i32), Basic
     }
 }
 
+/// Write the *An+B* notation for the given `(A, B)` pair, the way browsers serialize
+/// `:nth-child()` arguments: `A` is omitted when zero and written as `n` or `-n` when ±1,
+/// and `B` is omitted when zero (unless `A` is zero too) and otherwise carries its sign.
+///
+/// `parse_nth` does not keep track of keywords, so `even` and `odd` are written as `2n`
+/// and `2n+1`. The output parses back to the same pair.
+pub fn serialize_nth<W>(a: i32, b: i32, dest: &mut W) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    match a {
+        0 => return b.to_css(dest),
+        1 => dest.write_str("n")?,
+        -1 => dest.write_str("-n")?,
+        _ => {
+            a.to_css(dest)?;
+            dest.write_str("n")?
+        }
+    }
+    if b > 0 {
+        dest.write_str("+")?;
+    }
+    if b != 0 {
+        b.to_css(dest)?;
+    }
+    Ok(())
+}
+
 fn parse_b<'i, 't>(input: &mut Parser<'i, 't>, a: i32) -> Result<(i32, i32), BasicParseError<'i>> {
     let start = input.state();
     match input.next() {
@@ -3,8 +3,11 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use crate::cow_rc_str::CowRcStr;
+use crate::serializer::ToCss;
 use crate::tokenizer::{SourceLocation, SourcePosition, Token, Tokenizer};
 use smallvec::SmallVec;
+use std::error::Error;
+use std::fmt;
 use std::ops::BitOr;
 use std::ops::Range;
 
@@ -53,6 +56,29 @@ pub enum BasicParseErrorKind<'i> {
     QualifiedRuleInvalid,
 }
 
+impl<'i> fmt::Display for BasicParseErrorKind<'i> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BasicParseErrorKind::UnexpectedToken(Token::WhiteSpace(_)) => {
+                formatter.write_str("unexpected whitespace")
+            }
+            BasicParseErrorKind::UnexpectedToken(token) => {
+                formatter.write_str("unexpected token `")?;
+                token.to_css(formatter)?;
+                formatter.write_str("`")
+            }
+            BasicParseErrorKind::EndOfInput => formatter.write_str("unexpected end of input"),
+            BasicParseErrorKind::AtRuleInvalid(rule) => {
+                write!(formatter, "invalid @ rule encountered: `@{}`", rule)
+            }
+            BasicParseErrorKind::AtRuleBodyInvalid => formatter.write_str("invalid @ rule body"),
+            BasicParseErrorKind::QualifiedRuleInvalid => {
+                formatter.write_str("invalid qualified rule")
+            }
+        }
+    }
+}
+
 /// The fundamental parsing errors that can be triggered by built-in parsing routines.
 #[derive(Clone, Debug, PartialEq)]
 pub struct BasicParseError<'i> {
@@ -62,6 +88,14 @@ pub struct BasicParseError<'i> {
     pub location: SourceLocation,
 }
 
+impl<'i> fmt::Display for BasicParseError<'i> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.kind.fmt(formatter)
+    }
+}
+
+impl<'i> Error for BasicParseError<'i> {}
+
 impl<'i, T> From<BasicParseError<'i>> for ParseError<'i, T> {
     #[inline]
     fn from(this: BasicParseError<'i>) -> ParseError<'i, T> {
@@ -123,6 +157,15 @@ impl<'i, T> ParseErrorKind<'i, T> {
     }
 }
 
+impl<'i, T: fmt::Display> fmt::Display for ParseErrorKind<'i, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::Basic(basic) => basic.fmt(formatter),
+            ParseErrorKind::Custom(custom) => custom.fmt(formatter),
+        }
+    }
+}
+
 /// Extensible parse errors that can be encountered by client parsing implementations.
 #[derive(Clone, Debug, PartialEq)]
 pub struct ParseError<'i, E> {
@@ -156,6 +199,24 @@ impl<'i, T> ParseError<'i, T> {
     }
 }
 
+impl<'i, T: fmt::Display> fmt::Display for ParseError<'i, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.kind.fmt(formatter)
+    }
+}
+
+impl<'i, T> Error for ParseError<'i, T>
+where
+    T: Error,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.kind {
+            ParseErrorKind::Basic(_) => None,
+            ParseErrorKind::Custom(ref custom) => custom.source(),
+        }
+    }
+}
+
 /// The owned input for a parser.
 pub struct ParserInput<'i> {
     tokenizer: Tokenizer<'i>,
@@ -253,22 +253,72 @@ where
 {
     let mut chunk_start = 0;
     for (i, b) in value.bytes().enumerate() {
-        let hex = match b {
-            b'\0'..=b' ' | b'\x7F' => true,
-            b'(' | b')' | b'"' | b'\'' | b'\\' => false,
-            _ => continue,
-        };
+        if !needs_escape_in_unquoted_url(b) {
+            continue;
+        }
         dest.write_str(&value[chunk_start..i])?;
-        if hex {
-            hex_escape(b, dest)?;
-        } else {
+        if b.is_ascii_punctuation() {
             char_escape(b, dest)?;
+        } else {
+            hex_escape(b, dest)?;
         }
         chunk_start = i + 1;
     }
     dest.write_str(&value[chunk_start..])
 }
 
+/// Write a `url()` for the given URL, escaping content as necessary.
+///
+/// The URL is written unquoted, unless it contains characters that would
+/// have to be escaped in an unquoted URL (whitespace, quotes, parentheses,
+/// backslashes, or control characters), in which case it is written as a quoted string.
+/// Either way, `Parser::expect_url` returns the same value when parsing the result,
+/// except that NUL characters come back as U+FFFD REPLACEMENT CHARACTER.
+pub fn serialize_url<W>(value: &str, dest: &mut W) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    write_url(value, dest, serialize_string_with_fewest_escapes)
+}
+
+/// Same as `serialize_url`, but quote the URL (when it needs quoting)
+/// with `serialize_string_with_quote_preference`.
+pub fn serialize_url_with_quote_preference<W>(
+    value: &str,
+    preference: QuotePreference,
+    dest: &mut W,
+) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    write_url(value, dest, |value, dest| {
+        serialize_string_with_quote_preference(value, preference, dest)
+    })
+}
+
+fn write_url<W, F>(value: &str, dest: &mut W, serialize_quoted: F) -> fmt::Result
+where
+    W: fmt::Write,
+    F: FnOnce(&str, &mut W) -> fmt::Result,
+{
+    dest.write_str("url(")?;
+    if value.bytes().any(needs_escape_in_unquoted_url) {
+        serialize_quoted(value, dest)?;
+    } else {
+        dest.write_str(value)?;
+    }
+    dest.write_str(")")
+}
+
+/// Whitespace, control characters, and the punctuation that ends or quotes an unquoted URL.
+#[inline]
+fn needs_escape_in_unquoted_url(b: u8) -> bool {
+    matches!(
+        b,
+        b'\0'..=b' ' | b'\x7F' | b'(' | b')' | b'"' | b'\'' | b'\\'
+    )
+}
+
 /// Write a double-quoted CSS string token, escaping content as necessary.
 pub fn serialize_string<W>(value: &str, dest: &mut W) -> fmt::Result
 where
@@ -280,24 +330,100 @@ where
     Ok(())
 }
 
-/// A `fmt::Write` adapter that escapes text for writing as a double-quoted CSS string.
+/// Write a CSS string token, quoted with whichever of `"` or `'` requires fewer escapes.
+///
+/// Double quotes are used unless the value contains more `"` than `'` characters.
+pub fn serialize_string_with_fewest_escapes<W>(value: &str, dest: &mut W) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    let (double_quotes, single_quotes) = count_quotes(value);
+    serialize_string_with_quote(value, double_quotes > single_quotes, dest)
+}
+
+/// Which quote character `serialize_string_with_quote_preference` should use.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum QuotePreference {
+    /// Prefer `"`.
+    Double,
+    /// Prefer `'`.
+    Single,
+}
+
+/// Write a CSS string token, quoted with the preferred quote character.
+///
+/// The other quote character is only used when the value contains the preferred one
+/// but not the other, so that no quote needs escaping. Unlike
+/// `serialize_string_with_fewest_escapes`, the preferred quote is kept
+/// whenever the value contains both.
+pub fn serialize_string_with_quote_preference<W>(
+    value: &str,
+    preference: QuotePreference,
+    dest: &mut W,
+) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    let (double_quotes, single_quotes) = count_quotes(value);
+    let single_quoted = match preference {
+        QuotePreference::Double => double_quotes > 0 && single_quotes == 0,
+        QuotePreference::Single => single_quotes == 0 || double_quotes > 0,
+    };
+    serialize_string_with_quote(value, single_quoted, dest)
+}
+
+fn serialize_string_with_quote<W>(value: &str, single_quoted: bool, dest: &mut W) -> fmt::Result
+where
+    W: fmt::Write,
+{
+    if single_quoted {
+        dest.write_str("'")?;
+        CssStringWriter::new_single_quoted(dest).write_str(value)?;
+        dest.write_str("'")
+    } else {
+        serialize_string(value, dest)
+    }
+}
+
+fn count_quotes(value: &str) -> (usize, usize) {
+    value.bytes().fold((0, 0), |(double, single), b| match b {
+        b'"' => (double + 1, single),
+        b'\'' => (double, single + 1),
+        _ => (double, single),
+    })
+}
+
+/// A `fmt::Write` adapter that escapes text for writing as the contents of a CSS string,
+/// either double-quoted (`CssStringWriter::new`) or single-quoted (`new_single_quoted`).
 /// Quotes are not included.
 ///
+/// Since escaping happens on the fly, the content can be produced in pieces
+/// (for example with `write!`) without first collecting it into a `String`.
+///
 /// Typical usage:
 ///
-/// ```{rust,ignore}
-/// fn write_foo<W>(foo: &Foo, dest: &mut W) -> fmt::Result where W: fmt::Write {
+/// ```rust
+/// use cssparser::CssStringWriter;
+/// use std::fmt::{self, Write};
+///
+/// fn write_label<W>(name: &str, index: u32, dest: &mut W) -> fmt::Result where W: fmt::Write {
 ///     dest.write_str("\"")?;
 ///     {
 ///         let mut string_dest = CssStringWriter::new(dest);
-///         // Write into string_dest...
+///         write!(string_dest, "{} #{}", name, index)?;
 ///     }
 ///     dest.write_str("\"")?;
 ///     Ok(())
 /// }
+///
+/// let mut css = String::new();
+/// write_label("say \"hi\"", 2, &mut css).unwrap();
+/// assert_eq!(css, r#""say \"hi\" #2""#);
 /// ```
 pub struct CssStringWriter<'a, W> {
     inner: &'a mut W,
+    quote: u8,
+    escaped_quote: &'static str,
 }
 
 impl<'a, W> CssStringWriter<'a, W>
@@ -306,7 +432,20 @@ where
 {
     /// Wrap a text writer to create a `CssStringWriter`.
     pub fn new(inner: &'a mut W) -> CssStringWriter<'a, W> {
-        CssStringWriter { inner: inner }
+        CssStringWriter {
+            inner,
+            quote: b'"',
+            escaped_quote: "\\\"",
+        }
+    }
+
+    /// Same as `new`, but escape `'` instead of `"`, for writing a single-quoted string.
+    pub fn new_single_quoted(inner: &'a mut W) -> CssStringWriter<'a, W> {
+        CssStringWriter {
+            inner,
+            quote: b'\'',
+            escaped_quote: "\\'",
+        }
     }
 }
 
@@ -318,7 +457,7 @@ where
         let mut chunk_start = 0;
         for (i, b) in s.bytes().enumerate() {
             let escaped = match b {
-                b'"' => Some("\\\""),
+                _ if b == self.quote => Some(self.escaped_quote),
                 b'\\' => Some("\\\\"),
                 b'\0' => Some("\u{FFFD}"),
                 b'\x01'..=b'\x1F' | b'\x7F' => None,
@@ -335,6 +474,57 @@ where
     }
 }
 
+/// A `fmt::Write` adapter that writes UTF-8 text to an `io::Write`, such as a file or socket.
+///
+/// This lets `ToCss` output go straight to its destination without first being
+/// collected into a `String`. Since `fmt::Error` carries no details,
+/// the first I/O error is kept and returned by `IoWriter::finish`.
+///
+/// ```rust
+/// use cssparser::{IoWriter, ToCss, Token};
+///
+/// let mut writer = IoWriter::new(Vec::new());
+/// Token::Ident("a".into()).to_css(&mut writer).unwrap();
+/// assert_eq!(writer.finish().unwrap(), b"a");
+/// ```
+pub struct IoWriter<W> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W> IoWriter<W>
+where
+    W: io::Write,
+{
+    /// Wrap a byte writer to create an `IoWriter`.
+    pub fn new(inner: W) -> IoWriter<W> {
+        IoWriter { inner, error: None }
+    }
+
+    /// Return the underlying writer, or the first error it returned.
+    pub fn finish(self) -> io::Result<W> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.inner),
+        }
+    }
+}
+
+impl<W> fmt::Write for IoWriter<W>
+where
+    W: io::Write,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.error.is_some() {
+            return Err(fmt::Error);
+        }
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
 macro_rules! impl_tocss_for_int {
     ($T: ty) => {
         impl<'a> ToCss for $T {
@@ -441,14 +631,10 @@ impl TokenSerializationType {
                     | CDC
                     | OpenParen
             ),
-            AtKeywordOrHash | Dimension => matches!(
+            AtKeywordOrHash | Dimension | DelimHash | DelimMinus => matches!(
                 other.0,
                 Ident | Function | UrlOrBadUrl | DelimMinus | Number | Percentage | Dimension | CDC
             ),
-            DelimHash | DelimMinus => matches!(
-                other.0,
-                Ident | Function | UrlOrBadUrl | DelimMinus | Number | Percentage | Dimension
-            ),
             Number => matches!(
                 other.0,
                 Ident
@@ -459,8 +645,9 @@ impl TokenSerializationType {
                     | Percentage
                     | DelimPercent
                     | Dimension
+                    | CDC
             ),
-            DelimAt => matches!(other.0, Ident | Function | UrlOrBadUrl | DelimMinus),
+            DelimAt => matches!(other.0, Ident | Function | UrlOrBadUrl | DelimMinus | CDC),
             DelimDotOrPlus => matches!(other.0, Number | Percentage | Dimension),
             DelimAssorted | DelimAsterisk => matches!(other.0, DelimEquals),
             DelimBar => matches!(other.0, DelimEquals | DelimBar | DashMatch),
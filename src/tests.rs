@@ -13,11 +13,13 @@ use serde_json::{self, json, Map, Value};
 use self::test::Bencher;
 
 use super::{
-    parse_important, parse_nth, parse_one_declaration, parse_one_rule, stylesheet_encoding,
-    AtRuleParser, BasicParseError, BasicParseErrorKind, Color, CowRcStr,
-    DeclarationListParser, DeclarationParser, Delimiter, EncodingSupport, ParseError,
-    ParseErrorKind, Parser, ParserInput, ParserState, QualifiedRuleParser, RuleListParser,
-    SourceLocation, ToCss, Token, TokenSerializationType, UnicodeRange, RGBA,
+    parse_important, parse_nth, parse_one_declaration, parse_one_rule, serialize_nth,
+    serialize_string, serialize_string_with_fewest_escapes, serialize_string_with_quote_preference,
+    serialize_url, serialize_url_with_quote_preference, stylesheet_encoding, AtRuleParser,
+    BasicParseError, BasicParseErrorKind, Color, CowRcStr, CssStringWriter, DeclarationListParser,
+    DeclarationParser, Delimiter, EncodingSupport, IoWriter, ParseError, ParseErrorKind, Parser,
+    ParserInput, ParserState, QualifiedRuleParser, QuotePreference, RuleListParser, SourceLocation,
+    ToCss, Token, TokenSerializationType, UnicodeRange, RGBA,
 };
 
 macro_rules! JArray {
@@ -406,6 +408,59 @@ fn nth() {
     });
 }
 
+#[test]
+fn nth_serialization() {
+    let cases = [
+        ((0, 0), "0"),
+        ((0, 5), "5"),
+        ((0, -5), "-5"),
+        ((1, 0), "n"),
+        ((-1, 0), "-n"),
+        ((2, 0), "2n"),
+        ((2, 1), "2n+1"),
+        ((-1, 3), "-n+3"),
+        ((1, -1), "n-1"),
+        ((-3, -2), "-3n-2"),
+    ];
+    for &((a, b), expected) in &cases {
+        let mut serialized = String::new();
+        serialize_nth(a, b, &mut serialized).unwrap();
+        assert_eq!(serialized, expected);
+
+        let mut input = ParserInput::new(&serialized);
+        let mut parser = Parser::new(&mut input);
+        let result: Result<_, ParseError<()>> =
+            parser.parse_entirely(|i| parse_nth(i).map_err(Into::into));
+        assert_eq!(result.ok(), Some((a, b)));
+    }
+}
+
+#[test]
+fn unicode_range_serialization() {
+    let cases = [
+        ((0x41, 0x41), "U+41"),
+        ((0x400, 0x4FF), "U+4??"),
+        ((0x0, 0xFF), "U+??"),
+        ((0x0, 0xFFFFF), "U+?????"),
+        ((0x100000, 0x10FFFF), "U+10????"),
+        ((0x400, 0x4FE), "U+400-4FE"),
+        ((0x410, 0x4FF), "U+410-4FF"),
+        ((0x0, 0x10FFFF), "U+0-10FFFF"),
+        ((0x1F0, 0x2FF), "U+1F0-2FF"),
+    ];
+    for &((start, end), expected) in &cases {
+        let range = UnicodeRange { start, end };
+        let serialized = range.to_css_string();
+        assert_eq!(serialized, expected);
+
+        let mut input = ParserInput::new(&serialized);
+        let mut parser = Parser::new(&mut input);
+        let result: Result<_, ParseError<()>> =
+            parser.parse_entirely(|i| UnicodeRange::parse(i).map_err(Into::into));
+        assert_eq!(result.ok(), Some(range));
+    }
+}
+
 #[test]
 fn unicode_range() {
     run_json_tests(include_str!("css-parsing-tests/urange.json"), |input| {
@@ -519,6 +574,54 @@ fn serialize_bad_tokens() {
     assert!(parser.next().is_err());
 }
 
+#[test]
+fn serialize_token_pairs() {
+    use crate::tokenizer::Tokenizer;
+
+    fn tokenize(css: &str) -> Vec<Token<'_>> {
+        let mut tokenizer = Tokenizer::new(css);
+        let mut tokens = Vec::new();
+        while let Ok(token) = tokenizer.next() {
+            if !matches!(token, Token::Comment(_)) {
+                tokens.push(token)
+            }
+        }
+        tokens
+    }
+
+    let sources = [
+        "a", "-a", "--a", "@a", "#a", "#1", "1", "-1", "+1", ".5", "1%", "1px", "1e", "url(a)",
+        "f(", "(", ")", "[", "{", "-->", "<!--", "|=", "~=", "*=", "^=", "$=", "#", "-", "+", ".",
+        "@", "%", "*", "/", "|", "=", "!", "?", "<", ":", ",", "'s'",
+    ];
+    let tokens: Vec<Token> = sources
+        .iter()
+        .map(|source| {
+            let mut tokens = tokenize(source);
+            assert_eq!(tokens.len(), 1, "{:?}", source);
+            tokens.pop().unwrap()
+        })
+        .collect();
+    for first in &tokens {
+        for second in &tokens {
+            let mut serialized = first.to_css_string();
+            if first
+                .serialization_type()
+                .needs_separator_when_before(second.serialization_type())
+            {
+                serialized.push_str("/**/")
+            }
+            second.to_css(&mut serialized).unwrap();
+            assert_eq!(
+                tokenize(&serialized),
+                [first.clone(), second.clone()],
+                "{:?}",
+                serialized
+            );
+        }
+    }
+}
+
 #[test]
 fn serialize_current_color() {
     let c = Color::CurrentColor;
@@ -814,6 +917,225 @@ fn identifier_serialization() {
     );
 }
 
+#[test]
+fn string_serialization() {
+    fn serialize(value: &str, fewest_escapes: bool) -> String {
+        let mut s = String::new();
+        if fewest_escapes {
+            serialize_string_with_fewest_escapes(value, &mut s).unwrap();
+        } else {
+            serialize_string(value, &mut s).unwrap();
+        }
+        s
+    }
+
+    assert_eq!(serialize("", false), "\"\"");
+    assert_eq!(
+        serialize("a\"b'c\\d\ne\0", false),
+        "\"a\\\"b'c\\\\d\\a e\u{FFFD}\""
+    );
+    assert_eq!(serialize("it's", true), "\"it's\"");
+    assert_eq!(serialize("say \"hi\"", true), "'say \"hi\"'");
+    assert_eq!(serialize("\"'", true), "\"\\\"'\"");
+    assert_eq!(serialize("\"\"'", true), "'\"\"\\''");
+
+    for &value in &["", "it's", "say \"hi\"", "\"\"'", "a\\b\nc\u{7F}\u{1D306}"] {
+        for &fewest_escapes in &[false, true] {
+            let serialized = serialize(value, fewest_escapes);
+            let mut input = ParserInput::new(&serialized);
+            let mut parser = Parser::new(&mut input);
+            assert_eq!(parser.next(), Ok(&Token::QuotedString(value.into())));
+            assert!(parser.is_exhausted());
+        }
+    }
+}
+
+#[test]
+fn css_string_writer_chunks() {
+    use std::fmt::Write;
+
+    fn write_chunks(chunks: &[&str], single_quoted: bool) -> String {
+        let mut s = String::new();
+        {
+            let mut dest = if single_quoted {
+                CssStringWriter::new_single_quoted(&mut s)
+            } else {
+                CssStringWriter::new(&mut s)
+            };
+            for chunk in chunks {
+                write!(dest, "{}", chunk).unwrap();
+            }
+        }
+        s
+    }
+
+    let value = "a\"b'c\\\n\0é\u{1D306}z";
+    assert_eq!(
+        write_chunks(&[value], false),
+        "a\\\"b'c\\\\\\a \u{FFFD}é\u{1D306}z"
+    );
+    assert_eq!(
+        write_chunks(&[value], true),
+        "a\"b\\'c\\\\\\a \u{FFFD}é\u{1D306}z"
+    );
+    for &single_quoted in &[false, true] {
+        let expected = write_chunks(&[value], single_quoted);
+        for (split, _) in value.char_indices() {
+            let chunks = [&value[..split], &value[split..]];
+            assert_eq!(write_chunks(&chunks, single_quoted), expected);
+        }
+    }
+}
+
+#[test]
+fn url_serialization() {
+    fn serialize(value: &str) -> String {
+        let mut s = String::new();
+        serialize_url(value, &mut s).unwrap();
+        s
+    }
+
+    assert_eq!(serialize(""), "url()");
+    assert_eq!(serialize("a.png"), "url(a.png)");
+    assert_eq!(serialize("http://a.com/é?q#x"), "url(http://a.com/é?q#x)");
+    assert_eq!(serialize("a b.png"), "url(\"a b.png\")");
+    assert_eq!(serialize("a(1).png"), "url(\"a(1).png\")");
+    assert_eq!(serialize("it's.png"), "url(\"it's.png\")");
+    assert_eq!(serialize("\"quoted\".png"), "url('\"quoted\".png')");
+    assert_eq!(serialize("a\\b\n"), "url(\"a\\\\b\\a \")");
+
+    let values = [
+        "", "a.png", "a b", "a(1)", "it's", "\"", "a\\b", "\n\x7F", "é",
+    ];
+    for &value in &values {
+        let serialized = serialize(value);
+        let mut input = ParserInput::new(&serialized);
+        let mut parser = Parser::new(&mut input);
+        assert_eq!(parser.expect_url().unwrap(), value);
+        assert!(parser.is_exhausted());
+    }
+
+    let serialized = serialize("a\0b");
+    let mut input = ParserInput::new(&serialized);
+    let mut parser = Parser::new(&mut input);
+    assert_eq!(parser.expect_url().unwrap(), "a\u{FFFD}b");
+}
+
+#[test]
+fn quote_preference_serialization() {
+    fn serialize(value: &str, preference: QuotePreference) -> String {
+        let mut s = String::new();
+        serialize_string_with_quote_preference(value, preference, &mut s).unwrap();
+        s
+    }
+
+    assert_eq!(serialize("a", QuotePreference::Double), "\"a\"");
+    assert_eq!(serialize("a", QuotePreference::Single), "'a'");
+    assert_eq!(serialize("it's", QuotePreference::Single), "\"it's\"");
+    assert_eq!(
+        serialize("say \"hi\"", QuotePreference::Double),
+        "'say \"hi\"'"
+    );
+    assert_eq!(serialize("\"'", QuotePreference::Single), "'\"\\''");
+    // Both quotes present: keep the preferred one, even with more escapes.
+    assert_eq!(serialize("\"''", QuotePreference::Single), "'\"\\'\\''");
+    assert_eq!(serialize("\"\"'", QuotePreference::Double), "\"\\\"\\\"'\"");
+
+    for &value in &["", "it's", "say \"hi\"", "\"''", "a\\b\nc"] {
+        for &preference in &[QuotePreference::Double, QuotePreference::Single] {
+            let serialized = serialize(value, preference);
+            let mut input = ParserInput::new(&serialized);
+            let mut parser = Parser::new(&mut input);
+            assert_eq!(parser.next(), Ok(&Token::QuotedString(value.into())));
+            assert!(parser.is_exhausted());
+        }
+    }
+
+    let mut s = String::new();
+    serialize_url_with_quote_preference("a b.png", QuotePreference::Single, &mut s).unwrap();
+    assert_eq!(s, "url('a b.png')");
+    s.clear();
+    serialize_url_with_quote_preference("a.png", QuotePreference::Single, &mut s).unwrap();
+    assert_eq!(s, "url(a.png)");
+}
+
+#[test]
+fn io_writer_streaming() {
+    type Rule<'i> = (Vec<Token<'i>>, Vec<Token<'i>>);
+
+    struct TokenRules;
+
+    impl<'i> AtRuleParser<'i> for TokenRules {
+        type Prelude = ();
+        type AtRule = Rule<'i>;
+        type Error = ();
+    }
+
+    impl<'i> QualifiedRuleParser<'i> for TokenRules {
+        type Prelude = Vec<Token<'i>>;
+        type QualifiedRule = Rule<'i>;
+        type Error = ();
+
+        fn parse_prelude<'t>(
+            &mut self,
+            input: &mut Parser<'i, 't>,
+        ) -> Result<Vec<Token<'i>>, ParseError<'i, ()>> {
+            Ok(tokens(input))
+        }
+
+        fn parse_block<'t>(
+            &mut self,
+            prelude: Vec<Token<'i>>,
+            _: &ParserState,
+            input: &mut Parser<'i, 't>,
+        ) -> Result<Rule<'i>, ParseError<'i, ()>> {
+            Ok((prelude, tokens(input)))
+        }
+    }
+
+    fn tokens<'i>(input: &mut Parser<'i, '_>) -> Vec<Token<'i>> {
+        let mut tokens = vec![];
+        while let Ok(token) = input.next() {
+            tokens.push(token.clone());
+        }
+        tokens
+    }
+
+    fn write_rule<W: std::fmt::Write>(rule: &Rule, dest: &mut W) -> std::fmt::Result {
+        for token in &rule.0 {
+            token.to_css(dest)?;
+        }
+        dest.write_str("{")?;
+        for token in &rule.1 {
+            token.to_css(dest)?;
+        }
+        dest.write_str("}")
+    }
+
+    // Each rule is written as soon as it is parsed, without a `String` in between.
+    fn stream<W: std::io::Write>(css: &str, dest: W) -> std::io::Result<W> {
+        let mut input = ParserInput::new(css);
+        let mut parser = Parser::new(&mut input);
+        let mut writer = IoWriter::new(dest);
+        for rule in RuleListParser::new_for_stylesheet(&mut parser, TokenRules) {
+            if write_rule(&rule.unwrap(), &mut writer).is_err() {
+                break;
+            }
+        }
+        writer.finish()
+    }
+
+    let css = "a { color: red }\nb.c { margin: 0 }";
+    assert_eq!(
+        stream(css, Vec::new()).unwrap(),
+        b"a{color:red}b.c{margin:0}"
+    );
+
+    let mut buffer = [0; 4];
+    let error = stream(css, &mut buffer[..]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::WriteZero);
+}
+
 trait ToJson {
     fn to_json(&self) -> Value;
 }
@@ -1274,6 +1596,77 @@ fn parse_entirely_reports_first_error() {
     );
 }
 
+#[test]
+fn parse_error_display() {
+    #[derive(Debug)]
+    struct UnknownProperty<'i>(CowRcStr<'i>);
+
+    impl<'i> std::fmt::Display for UnknownProperty<'i> {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "unknown property `{}`", self.0)
+        }
+    }
+
+    impl<'i> std::error::Error for UnknownProperty<'i> {}
+
+    fn describe(error: &dyn std::error::Error) -> (String, bool) {
+        (error.to_string(), error.source().is_some())
+    }
+
+    // Not `'static`, so that errors borrowing from it are not either.
+    let css = String::from("a \"b c\"");
+    let mut input = ParserInput::new(&css);
+    let mut parser = Parser::new(&mut input);
+    let error = parser.expect_number().unwrap_err();
+    assert_eq!(describe(&error), ("unexpected token `a`".into(), false));
+    let error = parser.expect_ident().unwrap_err();
+    assert_eq!(error.to_string(), "unexpected token `\"b c\"`");
+    assert_eq!(
+        parser.expect_ident().unwrap_err().to_string(),
+        "unexpected end of input"
+    );
+
+    let error: ParseError<UnknownProperty> = error.into();
+    assert_eq!(
+        describe(&error),
+        ("unexpected token `\"b c\"`".into(), false)
+    );
+    let name = CowRcStr::from(&css[..1]);
+    let error: ParseError<UnknownProperty> = parser.new_custom_error(UnknownProperty(name));
+    assert_eq!(describe(&error), ("unknown property `a`".into(), false));
+
+    let mut input = ParserInput::new("\n  x");
+    let mut parser = Parser::new(&mut input);
+    let token = parser.next_including_whitespace().unwrap().clone();
+    assert_eq!(
+        parser.new_basic_unexpected_token_error(token).to_string(),
+        "unexpected whitespace"
+    );
+    let error = parser.new_basic_error(BasicParseErrorKind::AtRuleInvalid("foo".into()));
+    assert_eq!(error.to_string(), "invalid @ rule encountered: `@foo`");
+
+    #[derive(Debug)]
+    struct InvalidInteger(std::num::ParseIntError);
+
+    impl std::fmt::Display for InvalidInteger {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("invalid integer")
+        }
+    }
+
+    impl std::error::Error for InvalidInteger {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let inner = "x".parse::<i32>().unwrap_err();
+    let error: ParseError<InvalidInteger> = parser.new_custom_error(InvalidInteger(inner.clone()));
+    assert_eq!(describe(&error), ("invalid integer".into(), true));
+    let source = std::error::Error::source(&error).unwrap();
+    assert_eq!(source.downcast_ref(), Some(&inner));
+}
+
 #[test]
 fn parse_sourcemapping_comments() {
     let tests = vec![
@@ -172,6 +172,28 @@ impl ToCss for UnicodeRange {
     where
         W: fmt::Write,
     {
+        // Use `?` wildcards for the trailing hex digits that range over 0 to F,
+        // if the remaining leading digits are the same for start and end.
+        let mut wildcards = 0;
+        while wildcards < 6 {
+            let mask = (1 << (4 * (wildcards + 1))) - 1;
+            if self.start & mask != 0 || self.end & mask != mask {
+                break;
+            }
+            wildcards += 1;
+        }
+        let prefix = |value: u32| value >> (4 * wildcards);
+        if wildcards > 0 && prefix(self.start) == prefix(self.end) {
+            dest.write_str("U+")?;
+            if prefix(self.start) != 0 {
+                write!(dest, "{:X}", prefix(self.start))?;
+            }
+            for _ in 0..wildcards {
+                dest.write_str("?")?;
+            }
+            return Ok(());
+        }
+
         write!(dest, "U+{:X}", self.start)?;
         if self.end != self.start {
             write!(dest, "-{:X}", self.end)?;